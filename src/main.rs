@@ -1,38 +1,110 @@
-use rand::{rngs::ThreadRng, seq::IndexedRandom, Rng};
+use rand::{rngs::ThreadRng, Rng};
+use serde::Serialize;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
 
 /// All possible player choices in a rock paper scissors game
 #[repr(usize)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 enum RpsChoice {
     Rock = 0,
     Paper = 1,
     Scissors = 2
 }
 
-/// All possible players in this scenario
-#[repr(usize)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum RpsPlayer {
-    Player1 = 0,
-    Player2 = 1,
-    Player3 = 2,
-    Player4 = 3,
-    Player5 = 4
-}
-
-/// Winner of a rock paper scissors game
-#[derive(Debug)]
-enum RpsWinner {
-    Player(RpsPlayer),
+impl FromStr for RpsChoice {
+    type Err = String;
+
+    /// Parses "rock"/"paper"/"scissors" (case-insensitive) into a choice
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "rock" => Ok(RpsChoice::Rock),
+            "paper" => Ok(RpsChoice::Paper),
+            "scissors" => Ok(RpsChoice::Scissors),
+            other => Err(format!("'{other}' isn't rock, paper, or scissors"))
+        }
+    }
+}
+
+/// All possible choices, used for iterating when picking a counter move
+const ALL_CHOICES: [RpsChoice; 3] = [RpsChoice::Rock, RpsChoice::Paper, RpsChoice::Scissors];
+
+/// Returns the move that beats `choice`
+fn beats(choice: RpsChoice) -> RpsChoice {
+    match choice {
+        RpsChoice::Rock => RpsChoice::Paper,
+        RpsChoice::Paper => RpsChoice::Scissors,
+        RpsChoice::Scissors => RpsChoice::Rock,
+    }
+}
+
+/// Returns a uniformly random choice
+fn random_choice(rng: &mut ThreadRng) -> RpsChoice {
+    ALL_CHOICES[rng.random_range(0..ALL_CHOICES.len())]
+}
+
+/// Given a row of observed transition counts indexed by next choice, predicts the opponent's
+/// next move and returns the move that beats it, or None if the row has no data yet. Ties in
+/// the predicted choice are broken by preferring the move that beats the most tied options.
+fn counter_for_transition_row(row: [u32; 3]) -> Option<RpsChoice> {
+    if row.iter().all(|&count| count == 0) {
+        return None;
+    }
+
+    let max = *row.iter().max().unwrap();
+    let predicted: Vec<RpsChoice> = ALL_CHOICES.iter()
+        .filter(|&&choice| row[choice as usize] == max)
+        .cloned()
+        .collect();
+
+    ALL_CHOICES.iter()
+        .max_by_key(|&&mv| predicted.iter().filter(|&&p| beats(p) == mv).count())
+        .cloned()
+}
+
+/// Winner of a rock paper scissors round, generic over however a caller identifies a player
+#[derive(Debug, Serialize)]
+enum RpsWinner<P> {
+    Players(Vec<P>),
     Draw
 }
 
+/// Configurable points awarded per round, so different scoring rulesets can be compared.
+/// Outcome points (`win`/`draw`/`loss`) and the per-shape bonus for the choice thrown are
+/// both added to a player's score every round.
+#[derive(Debug, Clone, Copy)]
+struct ScoreConfig {
+    win: i64,
+    draw: i64,
+    loss: i64,
+    rock: i64,
+    paper: i64,
+    scissors: i64
+}
+
+impl ScoreConfig {
+    /// Creates a new scoring ruleset
+    pub fn new(win: i64, draw: i64, loss: i64, rock: i64, paper: i64, scissors: i64) -> Self {
+        ScoreConfig { win, draw, loss, rock, paper, scissors }
+    }
+
+    /// Points awarded for the shape thrown, independent of the outcome
+    fn shape_points(&self, choice: RpsChoice) -> i64 {
+        match choice {
+            RpsChoice::Rock => self.rock,
+            RpsChoice::Paper => self.paper,
+            RpsChoice::Scissors => self.scissors
+        }
+    }
+}
+
 /// Record of all matches for a player
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 struct RpsPlayerRecord {
     wins: i32,
     losses: i32,
     draws: i32,
+    score: i64,
     winning_choices: Vec<RpsChoice>,
     losing_choices: Vec<RpsChoice>,
     choices: Vec<RpsChoice>
@@ -41,33 +113,37 @@ struct RpsPlayerRecord {
 impl RpsPlayerRecord {
     /// Creates a blank record
     pub fn new() -> Self {
-        RpsPlayerRecord { 
-            wins: 0, 
-            losses: 0, 
+        RpsPlayerRecord {
+            wins: 0,
+            losses: 0,
             draws: 0,
-            winning_choices: Vec::new(), 
+            score: 0,
+            winning_choices: Vec::new(),
             losing_choices: Vec::new(),
             choices: Vec::new()
         }
     }
 
-    /// Records a win
-    pub fn win(&mut self, choice: RpsChoice) {
+    /// Records a win and awards the win + shape points from `config`
+    pub fn win(&mut self, choice: RpsChoice, config: &ScoreConfig) {
         self.wins += 1;
         self.winning_choices.push(choice);
         self.choices.push(choice);
+        self.score += config.win + config.shape_points(choice);
     }
 
-    /// Records a loss
-    pub fn lose(&mut self, choice: RpsChoice) {
+    /// Records a loss and awards the loss + shape points from `config`
+    pub fn lose(&mut self, choice: RpsChoice, config: &ScoreConfig) {
         self.losses += 1;
         self.losing_choices.push(choice);
         self.choices.push(choice);
+        self.score += config.loss + config.shape_points(choice);
     }
 
-    /// Record a draw
-    pub fn draw(&mut self) {
+    /// Record a draw and awards the draw + shape points from `config`
+    pub fn draw(&mut self, choice: RpsChoice, config: &ScoreConfig) {
         self.draws += 1;
+        self.score += config.draw + config.shape_points(choice);
     }
 
     /// Returns the most recent choice or None if no choices
@@ -112,7 +188,7 @@ impl RpsPlayerRecord {
     }
 
     fn print_list_stats(l: &Vec<RpsChoice>, preamble: String) {
-        println!("{preamble} [Rock {}x, Paper {}x, Scissors {}x]", 
+        println!("{preamble} [Rock {}x, Paper {}x, Scissors {}x]",
             l.iter().filter(|&&x| x == RpsChoice::Rock).count(),
             l.iter().filter(|&&x| x == RpsChoice::Paper).count(),
             l.iter().filter(|&&x| x == RpsChoice::Scissors).count());
@@ -125,210 +201,499 @@ impl RpsPlayerRecord {
 
     /// Prints record to stdout in a decent way
     pub fn print(&self) {
-        println!("Wins/Losses/Draws: {}/{}/{}, W/L = {:.2}", self.wins, self.losses, self.draws, self.ratio());
+        println!("Wins/Losses/Draws: {}/{}/{}, W/L = {:.2}, Score = {}", self.wins, self.losses, self.draws, self.ratio(), self.score);
         RpsPlayerRecord::print_list_stats(&self.winning_choices, "Wins".to_string());
         RpsPlayerRecord::print_list_stats(&self.losing_choices, "Losses".to_string());
         RpsPlayerRecord::print_list_stats(&self.choices, "Total Plays".to_string());
     }
 }
 
-/// A single rock paper scissors match
-#[derive(Debug)]
-struct RpsMatch {
-    player_1: RpsPlayer,
-    player_2: RpsPlayer,
-    player_1_choice: RpsChoice,
-    player_2_choice: RpsChoice
+/// A single rock paper scissors match between any number of simultaneous hands, generic over
+/// however a caller identifies a player (a plain roster index, in this simulator)
+#[derive(Debug, Clone, Serialize)]
+struct RpsMatch<P: Copy + PartialEq> {
+    hands: Vec<(P, RpsChoice)>
+}
+
+impl<P: Copy + PartialEq> RpsMatch<P> {
+    /// Creates a new RpsMatch from however many players threw a hand this round
+    pub fn new(hands: Vec<(P, RpsChoice)>) -> Self {
+        RpsMatch { hands }
+    }
+
+    /// Computes the winner(s) of a rock paper scissors match. With more than two hands the
+    /// round is a draw unless exactly two distinct choices were thrown, in which case every
+    /// player who threw the winning one of that pair wins.
+    pub fn winner(&self) -> RpsWinner<P> {
+        let mut distinct_choices = Vec::new();
+        for &(_, choice) in &self.hands {
+            if !distinct_choices.contains(&choice) {
+                distinct_choices.push(choice);
+            }
+        }
+
+        if distinct_choices.len() != 2 {
+            return RpsWinner::Draw;
+        }
+
+        let winning_choice = if beats(distinct_choices[1]) == distinct_choices[0] {
+            distinct_choices[0]
+        } else {
+            distinct_choices[1]
+        };
+
+        let winners = self.hands.iter()
+            .filter(|&&(_, choice)| choice == winning_choice)
+            .map(|&(player, _)| player)
+            .collect();
+
+        RpsWinner::Players(winners)
+    }
+}
+
+/// A JSON-serializable snapshot of a completed match, pairing the hands thrown with the
+/// resolved winner so external tooling doesn't have to recompute `winner()` itself
+#[derive(Debug, Serialize)]
+struct RpsMatchLog<P: Copy + PartialEq> {
+    hands: Vec<(P, RpsChoice)>,
+    winner: RpsWinner<P>
+}
+
+impl<P: Copy + PartialEq> RpsMatchLog<P> {
+    /// Builds a log entry from a completed match
+    pub fn new(rps_match: &RpsMatch<P>) -> Self {
+        RpsMatchLog {
+            hands: rps_match.hands.clone(),
+            winner: rps_match.winner()
+        }
+    }
+}
+
+/// A pluggable, stateful strategy. Strategies are no longer tied to a fixed player enum, so
+/// any custom bot can be registered with a `Simulation` as long as it implements this trait.
+/// `opponent_id` is whatever index the caller uses to identify the current opponent (a
+/// `Simulation` roster index, or a fixed id for a human in `--play` mode) — it lets strategies
+/// that adapt per-opponent (like `MarkovStrategy`) keep separate state for each one.
+trait Strategy {
+    /// Display name used in reports, leaderboards, and `--play` lookups
+    fn name(&self) -> &str;
+
+    /// Chooses this round's move given this player's own record and the opponent's record
+    fn choose(&mut self, own: &RpsPlayerRecord, opponent_id: usize, opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice;
+
+    /// Called after each round so stateful strategies can learn from what happened. Default is
+    /// a no-op; only strategies that adapt to their opponent need to override this.
+    fn observe(&mut self, _opponent_id: usize, _own_choice: RpsChoice, _opponent_previous_choice: Option<RpsChoice>, _opponent_choice: RpsChoice) {}
+}
+
+/// Plays completely at random
+struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn name(&self) -> &str { "Random Ramsy" }
+
+    fn choose(&mut self, _own: &RpsPlayerRecord, _opponent_id: usize, _opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice {
+        random_choice(rng)
+    }
+}
+
+/// Heavily weighted to scissors but still "random"
+struct ScissorHeavyStrategy;
+
+impl Strategy for ScissorHeavyStrategy {
+    fn name(&self) -> &str { "Scissor Sally" }
+
+    fn choose(&mut self, _own: &RpsPlayerRecord, _opponent_id: usize, _opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice {
+        let weight = rng.random_range(0..10);
+        if weight < 6 {
+            RpsChoice::Scissors
+        } else if weight < 8 {
+            RpsChoice::Rock
+        } else {
+            RpsChoice::Paper
+        }
+    }
+}
+
+/// Chooses the most common move in the opponent's losing record
+struct LoserLarryStrategy;
+
+impl Strategy for LoserLarryStrategy {
+    fn name(&self) -> &str { "Loser Larry" }
+
+    fn choose(&mut self, _own: &RpsPlayerRecord, _opponent_id: usize, opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice {
+        opponent.most_common_loss().unwrap_or_else(|| random_choice(rng))
+    }
+}
+
+/// Cycles going rock-paper-scissors ad nauseum
+struct GroovyGarthStrategy;
+
+impl Strategy for GroovyGarthStrategy {
+    fn name(&self) -> &str { "Groovy Garth" }
+
+    fn choose(&mut self, own: &RpsPlayerRecord, _opponent_id: usize, _opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice {
+        match own.most_recent_choice().unwrap_or_else(|| random_choice(rng)) {
+            RpsChoice::Paper => RpsChoice::Scissors,
+            RpsChoice::Rock => RpsChoice::Paper,
+            RpsChoice::Scissors => RpsChoice::Rock,
+        }
+    }
+}
+
+/// Copies the opponent's most recent successful move, or random if there isn't one yet
+struct CopycatStrategy;
+
+impl Strategy for CopycatStrategy {
+    fn name(&self) -> &str { "Copycat Candice" }
+
+    fn choose(&mut self, _own: &RpsPlayerRecord, _opponent_id: usize, opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice {
+        opponent.most_recent_win().unwrap_or_else(|| random_choice(rng))
+    }
+}
+
+/// Learns a separate order-1 transition pattern (previous choice -> next choice) per opponent,
+/// and plays the counter to that opponent's predicted next move
+struct MarkovStrategy {
+    // Keyed by opponent_id, since conflating every opponent into one table would mix together
+    // unrelated habits and make the prediction useless
+    transitions: std::collections::HashMap<usize, [[u32; 3]; 3]>
+}
+
+impl MarkovStrategy {
+    /// Creates a strategy with no transition history yet
+    pub fn new() -> Self {
+        MarkovStrategy { transitions: std::collections::HashMap::new() }
+    }
 }
 
-impl RpsMatch {
-    /// Creates a new RpsMatch
-    pub fn new(player_1: RpsPlayer, player_2: RpsPlayer, player_1_choice: RpsChoice, player_2_choice: RpsChoice) -> Self {
-        RpsMatch {
-            player_1,
-            player_2,
-            player_1_choice,
-            player_2_choice
+impl Strategy for MarkovStrategy {
+    fn name(&self) -> &str { "Markov Marvin" }
+
+    fn choose(&mut self, _own: &RpsPlayerRecord, opponent_id: usize, opponent: &RpsPlayerRecord, rng: &mut ThreadRng) -> RpsChoice {
+        match (opponent.most_recent_choice(), self.transitions.get(&opponent_id)) {
+            (Some(last), Some(rows)) => counter_for_transition_row(rows[last as usize]).unwrap_or_else(|| random_choice(rng)),
+            _ => random_choice(rng)
         }
     }
 
-    /// Computes the winner of a rock paper scissors match
-    pub fn winner(&self) -> RpsWinner {
-        match self.player_1_choice {
-            RpsChoice::Rock => {
-                match self.player_2_choice {
-                    RpsChoice::Rock => RpsWinner::Draw,
-                    RpsChoice::Paper => RpsWinner::Player(self.player_2),
-                    RpsChoice::Scissors => RpsWinner::Player(self.player_1)
+    fn observe(&mut self, opponent_id: usize, _own_choice: RpsChoice, opponent_previous_choice: Option<RpsChoice>, opponent_choice: RpsChoice) {
+        if let Some(prev) = opponent_previous_choice {
+            let rows = self.transitions.entry(opponent_id).or_insert([[0; 3]; 3]);
+            rows[prev as usize][opponent_choice as usize] += 1;
+        }
+    }
+}
+
+/// The roster of built-in strategies this simulator ships with
+fn default_strategies() -> Vec<Box<dyn Strategy>> {
+    vec!(
+        Box::new(RandomStrategy),
+        Box::new(ScissorHeavyStrategy),
+        Box::new(LoserLarryStrategy),
+        Box::new(GroovyGarthStrategy),
+        Box::new(CopycatStrategy),
+        Box::new(MarkovStrategy::new())
+    )
+}
+
+/// How a `Simulation` schedules matches across its roster
+enum Scheduler {
+    /// Pick two random players from the roster per match, `rounds` times total
+    RandomPairing { rounds: usize },
+    /// Every ordered pair in the roster plays `rounds_per_pair` times, for measurable fairness
+    RoundRobin { rounds_per_pair: usize },
+    /// Every round is a single free-for-all match among the whole roster, `rounds` times total
+    AllVsAll { rounds: usize }
+}
+
+fn pop_random_index(rng: &mut ThreadRng, indices: &mut Vec<usize>) -> usize {
+    let idx = rng.random_range(0..indices.len());
+    indices.remove(idx)
+}
+
+/// A configurable tournament: owns an arbitrary roster of strategies and a scheduler that
+/// decides which players face off, so the roster is no longer capped by a fixed player enum
+struct Simulation {
+    strategies: Vec<Box<dyn Strategy>>,
+    records: Vec<RpsPlayerRecord>,
+    matches: Vec<RpsMatch<usize>>,
+    scheduler: Scheduler
+}
+
+impl Simulation {
+    /// Creates a simulation from a roster of strategies and a scheduler
+    pub fn new(strategies: Vec<Box<dyn Strategy>>, scheduler: Scheduler) -> Self {
+        let records = strategies.iter().map(|_| RpsPlayerRecord::new()).collect();
+        Simulation { strategies, records, matches: Vec::new(), scheduler }
+    }
+
+    /// Runs every scheduled match, updating each player's record and score
+    pub fn run(&mut self, rng: &mut ThreadRng, score_config: &ScoreConfig) {
+        match self.scheduler {
+            Scheduler::RandomPairing { rounds } => {
+                for _ in 0..rounds {
+                    let mut indices: Vec<usize> = (0..self.strategies.len()).collect();
+                    let i = pop_random_index(rng, &mut indices);
+                    let j = pop_random_index(rng, &mut indices);
+                    self.play_pair(i, j, rng, score_config);
                 }
             },
-            RpsChoice::Paper => {
-                match self.player_2_choice {
-                    RpsChoice::Rock => RpsWinner::Player(self.player_1),
-                    RpsChoice::Paper => RpsWinner::Draw,
-                    RpsChoice::Scissors => RpsWinner::Player(self.player_2)
+            Scheduler::RoundRobin { rounds_per_pair } => {
+                for i in 0..self.strategies.len() {
+                    for j in 0..self.strategies.len() {
+                        if i == j {
+                            continue;
+                        }
+                        for _ in 0..rounds_per_pair {
+                            self.play_pair(i, j, rng, score_config);
+                        }
+                    }
                 }
             },
-            RpsChoice::Scissors => {
-                match self.player_2_choice {
-                    RpsChoice::Rock => RpsWinner::Player(self.player_2),
-                    RpsChoice::Paper => RpsWinner::Player(self.player_1),
-                    RpsChoice::Scissors => RpsWinner::Draw
+            Scheduler::AllVsAll { rounds } => {
+                for _ in 0..rounds {
+                    self.play_all(rng, score_config);
                 }
             }
         }
     }
 
-    /// Prints the match and the results to stdout
-    #[allow(dead_code)]
-    pub fn result(&self) {
-        println!("Match: {:?} vs {:?} => {:?}", self.player_1, self.player_2, self.winner());
-    }
-}
-
-/// Returns a choice depending on a lot of things
-fn strategy(rng: &mut ThreadRng, player: RpsPlayer, player_record: &RpsPlayerRecord, _opponent: RpsPlayer, opponent_record: &RpsPlayerRecord) -> RpsChoice {
-    let choices = vec!(
-        RpsChoice::Rock,
-        RpsChoice::Paper,
-        RpsChoice::Scissors
-    );
-    let random_choice = choices.choose(rng).unwrap().clone();
-
-    match player {
-        // player 1 is completely random
-        RpsPlayer::Player1 => {
-            random_choice
-        },
-        // player 2 is heavily weighted to scissors but still "random"
-        RpsPlayer::Player2 => {
-            let weight = rng.random_range(0..10);
-            let choice;
-            if weight < 6 {
-                choice = RpsChoice::Scissors;
-            } else if weight < 8 {
-                choice = RpsChoice::Rock;
-            } else {
-                choice = RpsChoice::Paper;
-            }
-            choice
-        },
-        // player 3 will choose the most common move in their opponents losing record
-        RpsPlayer::Player3 => {
-            match opponent_record.most_common_loss() {
-                Some(choice) => choice,
-                None => random_choice
-            }
-        },
-        // player 4 will cycle going RPS ad nauseum
-        RpsPlayer::Player4 => {
-            match player_record.most_recent_choice().unwrap_or(random_choice) {
-                RpsChoice::Paper => RpsChoice::Scissors,
-                RpsChoice::Rock => RpsChoice::Paper,
-                RpsChoice::Scissors => RpsChoice::Rock,
-            }
-        },
-        // player 5 will copy their opponents most recent successful move, or random if there are no recorded moves yet
-        RpsPlayer::Player5 => {
-            match opponent_record.most_recent_win() {
-                Some(choice) => choice,
-                None => random_choice
+    /// Plays a single head-to-head match between roster indices `i` and `j`
+    fn play_pair(&mut self, i: usize, j: usize, rng: &mut ThreadRng, score_config: &ScoreConfig) {
+        let choice_i = self.strategies[i].choose(&self.records[i], j, &self.records[j], rng);
+        let choice_j = self.strategies[j].choose(&self.records[j], i, &self.records[i], rng);
+
+        // Let each strategy learn this round's transition before the records below mutate history
+        let prev_i = self.records[i].most_recent_choice();
+        let prev_j = self.records[j].most_recent_choice();
+        self.strategies[i].observe(j, choice_i, prev_j, choice_j);
+        self.strategies[j].observe(i, choice_j, prev_i, choice_i);
+
+        let rps_match = RpsMatch::new(vec!((i, choice_i), (j, choice_j)));
+        self.apply_result(&rps_match, score_config);
+        self.matches.push(rps_match);
+    }
+
+    /// Plays a single free-for-all match among the entire roster. Since a strategy only ever
+    /// sees one designated opponent's record, each player is paired for that purpose with the
+    /// next player in roster order (wrapping around): this only drives `choose`/`observe`,
+    /// not `winner()`, which still resolves the round across all N hands.
+    fn play_all(&mut self, rng: &mut ThreadRng, score_config: &ScoreConfig) {
+        let n = self.strategies.len();
+        let mut hands = Vec::with_capacity(n);
+        for i in 0..n {
+            let opponent = (i + 1) % n;
+            let choice = self.strategies[i].choose(&self.records[i], opponent, &self.records[opponent], rng);
+            hands.push((i, choice));
+        }
+
+        for &(i, choice) in &hands {
+            let opponent = (i + 1) % n;
+            let prev_opponent_choice = self.records[opponent].most_recent_choice();
+            let (_, opponent_choice) = hands[opponent];
+            self.strategies[i].observe(opponent, choice, prev_opponent_choice, opponent_choice);
+        }
+
+        let rps_match = RpsMatch::new(hands);
+        self.apply_result(&rps_match, score_config);
+        self.matches.push(rps_match);
+    }
+
+    /// Records wins, losses, and draws from a completed match
+    fn apply_result(&mut self, rps_match: &RpsMatch<usize>, score_config: &ScoreConfig) {
+        match rps_match.winner() {
+            RpsWinner::Players(winners) => {
+                for &(player, choice) in &rps_match.hands {
+                    if winners.contains(&player) {
+                        self.records[player].win(choice, score_config);
+                    } else {
+                        self.records[player].lose(choice, score_config);
+                    }
+                }
+            },
+            RpsWinner::Draw => {
+                for &(player, choice) in &rps_match.hands {
+                    self.records[player].draw(choice, score_config);
+                }
             }
         }
     }
+
+    /// Prints every player's full record to stdout
+    pub fn print_details(&self) {
+        println!("===========================================================================");
+        for (strategy, record) in self.strategies.iter().zip(self.records.iter()) {
+            println!("{}:", strategy.name());
+            record.print();
+            println!("===========================================================================");
+        }
+    }
+
+    /// Prints a leaderboard of every registered strategy sorted by score
+    pub fn print_leaderboard(&self) {
+        let mut order: Vec<usize> = (0..self.strategies.len()).collect();
+        order.sort_by(|&a, &b| self.records[b].score.cmp(&self.records[a].score));
+
+        println!("Leaderboard:");
+        for (rank, &i) in order.iter().enumerate() {
+            println!("{}. {} - {} points", rank + 1, self.strategies[i].name(), self.records[i].score);
+        }
+    }
 }
 
-fn pop_random_element(rng: &mut ThreadRng, list: &mut Vec<RpsPlayer>) -> RpsPlayer {
-    let idx = rng.random_range(0..list.len());
-    list.remove(idx)
+/// Serializes every match and each player's final record to JSON at `path`
+fn write_json_output(path: &str, sim: &Simulation) {
+    // Resolve roster indices to strategy names before serializing, so a match log is
+    // self-describing instead of forcing external tooling to guess which index is who
+    let names: Vec<&str> = sim.strategies.iter().map(|strategy| strategy.name()).collect();
+    let match_logs: Vec<RpsMatchLog<&str>> = sim.matches.iter()
+        .map(|rps_match| {
+            let named_hands = rps_match.hands.iter().map(|&(i, choice)| (names[i], choice)).collect();
+            RpsMatchLog::new(&RpsMatch::new(named_hands))
+        })
+        .collect();
+    let records: std::collections::HashMap<&str, &RpsPlayerRecord> = sim.strategies.iter()
+        .map(|strategy| strategy.name())
+        .zip(sim.records.iter())
+        .collect();
+
+    let output = serde_json::json!({
+        "matches": match_logs,
+        "records": records
+    });
+    let json = serde_json::to_string_pretty(&output).expect("match log should always serialize");
+
+    match std::fs::write(path, json) {
+        Ok(()) => println!("Wrote JSON match log to {path}"),
+        Err(e) => eprintln!("Failed to write JSON output to {path}: {e}")
+    }
 }
 
-fn main() {
-    let mut matches = Vec::new();
+/// Finds a registered strategy by a case-insensitive substring match against its name, used to
+/// resolve `--play <name>` now that strategies aren't keyed by a fixed player enum
+fn find_strategy_index(strategies: &[Box<dyn Strategy>], query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    strategies.iter().position(|strategy| strategy.name().to_lowercase().contains(&query))
+}
+
+/// Parses an optional `--scheduler <random|round-robin|all-vs-all>` argument
+fn parse_scheduler(args: &[String]) -> Scheduler {
+    let kind = args.iter()
+        .position(|arg| arg == "--scheduler")
+        .and_then(|i| args.get(i + 1))
+        .map(|s| s.as_str())
+        .unwrap_or("random");
+
+    match kind {
+        "round-robin" => Scheduler::RoundRobin { rounds_per_pair: 50 },
+        "all-vs-all" => Scheduler::AllVsAll { rounds: 1000 },
+        "random" => Scheduler::RandomPairing { rounds: 1000 },
+        other => {
+            eprintln!("Unrecognized --scheduler '{other}'. Falling back to random. Try one of: random, round-robin, all-vs-all.");
+            Scheduler::RandomPairing { rounds: 1000 }
+        }
+    }
+}
+
+/// Runs an interactive REPL where a human plays rock-paper-scissors against `bot` from stdin
+fn play_interactive(bot: &mut dyn Strategy, score_config: &ScoreConfig) {
     let mut rng = rand::rng();
-    let mut player_records = vec!(
-        RpsPlayerRecord::new(),
-        RpsPlayerRecord::new(),
-        RpsPlayerRecord::new(),
-        RpsPlayerRecord::new(),
-        RpsPlayerRecord::new()
-    );
+    let mut bot_record = RpsPlayerRecord::new();
+    let mut human_record = RpsPlayerRecord::new();
+    let stdin = io::stdin();
 
-    let match_count = 1000;
+    println!("Playing against {}. Type rock, paper, or scissors (or exit to quit).", bot.name());
+    print!("> ");
+    io::stdout().flush().ok();
 
-    // Compile random matches
-    let start_time = std::time::Instant::now();
-    for _ in 0..match_count {
-        // Pick two random players
-        let mut players = vec!(
-            RpsPlayer::Player1,
-            RpsPlayer::Player2,
-            RpsPlayer::Player3,
-            RpsPlayer::Player4,
-            RpsPlayer::Player5
-        );
-        let player_1 = pop_random_element(&mut rng, &mut players);
-        let player_2 = pop_random_element(&mut rng, &mut players);
-
-        // Pick each player choice
-        let choice_1 = strategy(&mut rng, player_1, &player_records[player_1 as usize], player_2, &player_records[player_2 as usize]);
-        let choice_2 = strategy(&mut rng, player_2, &player_records[player_2 as usize], player_1, &player_records[player_1 as usize]);
-
-        // Create a match where the 2 random players picked two random moves
-        let rps_match = RpsMatch::new(
-            player_1, 
-            player_2, 
-            choice_1,
-            choice_2,
-        );
-
-        // Record wins and losses
+    let mut line = String::new();
+    while stdin.lock().read_line(&mut line).unwrap_or(0) > 0 {
+        let input = line.trim();
+
+        if input.eq_ignore_ascii_case("exit") {
+            break;
+        }
+
+        let human_choice = match RpsChoice::from_str(input) {
+            Ok(choice) => choice,
+            Err(message) => {
+                println!("{message}. Try again.");
+                line.clear();
+                print!("> ");
+                io::stdout().flush().ok();
+                continue;
+            }
+        };
+
+        let bot_choice = bot.choose(&bot_record, 1, &human_record, &mut rng);
+
+        let prev_human_choice = human_record.most_recent_choice();
+        bot.observe(1, bot_choice, prev_human_choice, human_choice);
+
+        let rps_match = RpsMatch::new(vec!((0usize, bot_choice), (1usize, human_choice)));
         match rps_match.winner() {
-            RpsWinner::Player(p) if p == player_1 => {
-                player_records[player_1 as usize].win(choice_1);
-                player_records[player_2 as usize].lose(choice_2);
-            },
-            RpsWinner::Player(p) if p == player_2 => {
-                player_records[player_2 as usize].win(choice_2);
-                player_records[player_1 as usize].lose(choice_1);
+            RpsWinner::Players(winners) if winners.contains(&0) => {
+                bot_record.win(bot_choice, score_config);
+                human_record.lose(human_choice, score_config);
+                println!("Bot played {bot_choice:?}. Bot wins this round!");
             },
-            _ => {
-                player_records[player_1 as usize].draw();
-                player_records[player_2 as usize].draw();
+            RpsWinner::Players(_) => {
+                bot_record.lose(bot_choice, score_config);
+                human_record.win(human_choice, score_config);
+                println!("Bot played {bot_choice:?}. You win this round!");
             },
+            RpsWinner::Draw => {
+                bot_record.draw(bot_choice, score_config);
+                human_record.draw(human_choice, score_config);
+                println!("Bot played {bot_choice:?}. It's a draw.");
+            }
         }
 
-        matches.push(rps_match);
+        println!("Score - you: {}, bot: {}", human_record.score, bot_record.score);
+
+        line.clear();
+        print!("> ");
+        io::stdout().flush().ok();
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    let json_output_path = args.iter()
+        .position(|arg| arg == "--json")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+    let play_query = args.iter()
+        .position(|arg| arg == "--play")
+        .and_then(|i| args.get(i + 1))
+        .cloned();
+
+    let score_config = ScoreConfig::new(6, 3, 0, 1, 2, 3);
+    let mut strategies = default_strategies();
+
+    if let Some(query) = play_query {
+        match find_strategy_index(&strategies, &query) {
+            Some(idx) => play_interactive(strategies[idx].as_mut(), &score_config),
+            None => eprintln!("No strategy matches '{query}'. Try one of: Random, Scissor, Loser, Groovy, Copycat, Markov.")
+        }
+        return;
     }
-    let end_time = std::time::Instant::now();
-    let between_time = end_time.duration_since(start_time).as_micros();
 
-    println!("Time to create {} records: {:.3}ms", match_count, between_time as f64 / 1000.0);
+    let scheduler = parse_scheduler(&args);
+    let mut sim = Simulation::new(strategies, scheduler);
+    let mut rng = rand::rng();
 
-    let player_strats = vec!(
-        "Random Ramsy is completely random",
-        "Scissor Sally is heavily weighted to scissors and otherwise random",
-        "Loser Larry will choose the most common move in their opponents losing record",
-        "Groovy Garth will cycle going rock-paper-scissors ad nauseum",
-        "Copycat Candice will copy their opponents most recent successful move"
-    );
+    let start_time = std::time::Instant::now();
+    sim.run(&mut rng, &score_config);
+    let between_time = start_time.elapsed().as_micros();
 
-    println!("===========================================================================");
-    for i in 0..5 {
-        println!("{}:", player_strats[i]);
-        player_records[i].print();
-        println!("===========================================================================");
-    }
+    println!("Time to run {} matches: {:.3}ms", sim.matches.len(), between_time as f64 / 1000.0);
 
-    // Print just the W/L to make it easier to find
-    println!("Random Ramsy W/L: {:.2}\n\
-            Scissor Sally W/L: {:.2}\n\
-            Loser Larry W/L: {:.2}\n\
-            Groovy Garth W/L: {:.2}\n\
-            Copycat Candice W/L: {:.2}",
-            player_records[0].ratio(), 
-            player_records[1].ratio(), 
-            player_records[2].ratio(), 
-            player_records[3].ratio(), 
-            player_records[4].ratio());
+    sim.print_details();
+    sim.print_leaderboard();
+
+    if let Some(path) = json_output_path {
+        write_json_output(&path, &sim);
+    }
 }